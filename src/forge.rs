@@ -0,0 +1,387 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use octocrab::{models::IssueState as OctoIssueState, params, Octocrab};
+use serde::{Deserialize, Serialize};
+
+use crate::Repository;
+
+#[derive(Debug, Clone)]
+pub struct ForgeIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: ForgeIssueState,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForgeIssueState {
+    Open,
+    Closed,
+}
+
+#[derive(Debug)]
+pub struct ForgeError(pub String);
+
+impl std::fmt::Display for ForgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ForgeError {}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+}
+
+impl Default for ForgeKind {
+    fn default() -> Self {
+        ForgeKind::GitHub
+    }
+}
+
+#[async_trait]
+pub trait Forge: Send + Sync {
+    async fn verify_repo(&self, repo: &Repository) -> Result<(), ForgeError>;
+    async fn create_issue(&self, repo: &Repository, title: &str, body: &str) -> Result<ForgeIssue, ForgeError>;
+    async fn list_issues(&self, repo: &Repository, since: Option<DateTime<Utc>>) -> Result<Vec<ForgeIssue>, ForgeError>;
+    async fn close_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError>;
+    async fn reopen_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError>;
+    async fn update_issue(&self, repo: &Repository, number: u64, title: &str, body: &str) -> Result<(), ForgeError>;
+    async fn add_labels(&self, repo: &Repository, number: u64, labels: &[String]) -> Result<(), ForgeError>;
+}
+
+pub struct GitHubForge {
+    client: Octocrab,
+}
+
+impl GitHubForge {
+    pub fn new(token: String) -> Result<Self, ForgeError> {
+        Octocrab::builder()
+            .personal_token(token)
+            .build()
+            .map(|client| GitHubForge { client })
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+}
+
+fn octo_issue_to_forge(issue: octocrab::models::issues::Issue) -> ForgeIssue {
+    ForgeIssue {
+        number: issue.number,
+        title: issue.title,
+        body: issue.body,
+        state: if issue.state == OctoIssueState::Closed {
+            ForgeIssueState::Closed
+        } else {
+            ForgeIssueState::Open
+        },
+        created_at: issue.created_at,
+        updated_at: issue.updated_at,
+        labels: issue.labels.into_iter().map(|l| l.name).collect(),
+    }
+}
+
+#[async_trait]
+impl Forge for GitHubForge {
+    async fn verify_repo(&self, repo: &Repository) -> Result<(), ForgeError> {
+        self.client
+            .repos(&repo.owner, &repo.name)
+            .get()
+            .await
+            .map(|_| ())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn create_issue(&self, repo: &Repository, title: &str, body: &str) -> Result<ForgeIssue, ForgeError> {
+        self.client
+            .issues(&repo.owner, &repo.name)
+            .create(title)
+            .body(body)
+            .send()
+            .await
+            .map(octo_issue_to_forge)
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn list_issues(&self, repo: &Repository, since: Option<DateTime<Utc>>) -> Result<Vec<ForgeIssue>, ForgeError> {
+        let mut request = self
+            .client
+            .issues(&repo.owner, &repo.name)
+            .list()
+            .state(params::State::All)
+            .per_page(100);
+
+        if let Some(since) = since {
+            request = request.since(since);
+        }
+
+        let first_page = request.send().await.map_err(|e| ForgeError(e.to_string()))?;
+
+        self.client
+            .all_pages(first_page)
+            .await
+            .map(|issues| issues.into_iter().map(octo_issue_to_forge).collect())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn close_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError> {
+        self.client
+            .issues(&repo.owner, &repo.name)
+            .update(number)
+            .state(OctoIssueState::Closed)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn reopen_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError> {
+        self.client
+            .issues(&repo.owner, &repo.name)
+            .update(number)
+            .state(OctoIssueState::Open)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn update_issue(&self, repo: &Repository, number: u64, title: &str, body: &str) -> Result<(), ForgeError> {
+        self.client
+            .issues(&repo.owner, &repo.name)
+            .update(number)
+            .title(title)
+            .body(body)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn add_labels(&self, repo: &Repository, number: u64, labels: &[String]) -> Result<(), ForgeError> {
+        let issues = self.client.issues(&repo.owner, &repo.name);
+
+        let existing: Vec<String> = issues
+            .list_labels_for_repo()
+            .send()
+            .await
+            .map(|page| page.items.into_iter().map(|l| l.name).collect())
+            .map_err(|e| ForgeError(e.to_string()))?;
+
+        for label in labels {
+            if !existing.iter().any(|l| l == label) {
+                let _ = issues.create_label(label, "ededed", "").await;
+            }
+        }
+
+        issues
+            .add_labels(number, labels)
+            .await
+            .map(|_| ())
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+}
+
+pub struct GitLabForge {
+    client: reqwest::Client,
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    labels: Vec<String>,
+}
+
+impl GitLabIssue {
+    fn into_forge_issue(self) -> ForgeIssue {
+        ForgeIssue {
+            number: self.iid,
+            title: self.title,
+            body: self.description,
+            state: if self.state == "closed" {
+                ForgeIssueState::Closed
+            } else {
+                ForgeIssueState::Open
+            },
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            labels: self.labels,
+        }
+    }
+}
+
+impl GitLabForge {
+    pub fn new(token: String) -> Self {
+        GitLabForge {
+            client: reqwest::Client::new(),
+            token,
+        }
+    }
+
+    fn base_url(repo: &Repository) -> String {
+        repo.base_url
+            .clone()
+            .unwrap_or_else(|| "https://gitlab.com".to_string())
+    }
+
+    fn project_path(repo: &Repository) -> String {
+        urlencoding::encode(&format!("{}/{}", repo.owner, repo.name)).into_owned()
+    }
+
+    fn issues_url(repo: &Repository) -> String {
+        format!(
+            "{}/api/v4/projects/{}/issues",
+            Self::base_url(repo),
+            Self::project_path(repo)
+        )
+    }
+}
+
+#[async_trait]
+impl Forge for GitLabForge {
+    async fn verify_repo(&self, repo: &Repository) -> Result<(), ForgeError> {
+        let url = format!(
+            "{}/api/v4/projects/{}",
+            Self::base_url(repo),
+            Self::project_path(repo)
+        );
+
+        let response = self
+            .client
+            .get(url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .send()
+            .await
+            .map_err(|e| ForgeError(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ForgeError(format!("GitLab returned {}", response.status())))
+        }
+    }
+
+    async fn create_issue(&self, repo: &Repository, title: &str, body: &str) -> Result<ForgeIssue, ForgeError> {
+        self.client
+            .post(Self::issues_url(repo))
+            .header("PRIVATE-TOKEN", &self.token)
+            .form(&[("title", title), ("description", body)])
+            .send()
+            .await
+            .map_err(|e| ForgeError(e.to_string()))?
+            .json::<GitLabIssue>()
+            .await
+            .map(GitLabIssue::into_forge_issue)
+            .map_err(|e| ForgeError(e.to_string()))
+    }
+
+    async fn list_issues(&self, repo: &Repository, since: Option<DateTime<Utc>>) -> Result<Vec<ForgeIssue>, ForgeError> {
+        let mut all_issues = Vec::new();
+        let mut page = 1u32;
+
+        loop {
+            let mut request = self
+                .client
+                .get(Self::issues_url(repo))
+                .header("PRIVATE-TOKEN", &self.token)
+                .query(&[("per_page", "100"), ("scope", "all")])
+                .query(&[("page", page)]);
+
+            if let Some(since) = since {
+                request = request.query(&[("updated_after", since.to_rfc3339())]);
+            }
+
+            let issues: Vec<GitLabIssue> = request
+                .send()
+                .await
+                .map_err(|e| ForgeError(e.to_string()))?
+                .json()
+                .await
+                .map_err(|e| ForgeError(e.to_string()))?;
+
+            if issues.is_empty() {
+                break;
+            }
+
+            all_issues.extend(issues.into_iter().map(GitLabIssue::into_forge_issue));
+            page += 1;
+        }
+
+        Ok(all_issues)
+    }
+
+    async fn close_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError> {
+        self.set_issue_state(repo, number, "close").await
+    }
+
+    async fn reopen_issue(&self, repo: &Repository, number: u64) -> Result<(), ForgeError> {
+        self.set_issue_state(repo, number, "reopen").await
+    }
+
+    async fn update_issue(&self, repo: &Repository, number: u64, title: &str, body: &str) -> Result<(), ForgeError> {
+        let url = format!("{}/{}", Self::issues_url(repo), number);
+        let response = self
+            .client
+            .put(url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .form(&[("title", title), ("description", body)])
+            .send()
+            .await
+            .map_err(|e| ForgeError(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ForgeError(format!("GitLab returned {}", response.status())))
+        }
+    }
+
+    async fn add_labels(&self, repo: &Repository, number: u64, labels: &[String]) -> Result<(), ForgeError> {
+        let url = format!("{}/{}", Self::issues_url(repo), number);
+        let response = self
+            .client
+            .put(url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .form(&[("add_labels", labels.join(","))])
+            .send()
+            .await
+            .map_err(|e| ForgeError(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ForgeError(format!("GitLab returned {}", response.status())))
+        }
+    }
+}
+
+impl GitLabForge {
+    async fn set_issue_state(&self, repo: &Repository, number: u64, state_event: &str) -> Result<(), ForgeError> {
+        let url = format!("{}/{}", Self::issues_url(repo), number);
+        let response = self
+            .client
+            .put(url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .form(&[("state_event", state_event)])
+            .send()
+            .await
+            .map_err(|e| ForgeError(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ForgeError(format!("GitLab returned {}", response.status())))
+        }
+    }
+}
@@ -1,13 +1,165 @@
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use indicatif::{ProgressBar, ProgressStyle};
-use octocrab::Octocrab;
 use serde::{Deserialize, Serialize};
-use std::{fs, thread, time::Duration};
+use std::{env, fs, process::Command, thread, time::Duration};
 use directories::ProjectDirs;
 use webbrowser;
 
+mod forge;
+use forge::{Forge, ForgeIssueState, ForgeKind, GitHubForge, GitLabForge};
+
+mod notify;
+use notify::{NotificationConfig, NotifyChannel};
+
+fn parse_due_date(raw: &str) -> Option<NaiveDate> {
+    fuzzydate::parse(raw).ok().map(|dt| dt.date())
+}
+
+fn parse_timestamp(raw: &str) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC)
+}
+
+fn parse_tags(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+// Splits `$EDITOR`-style values like "code --wait" into a program and its
+// arguments; `Command::new` won't shell-split them on its own.
+fn split_editor_command(raw: &str) -> (String, Vec<String>) {
+    let mut parts = raw.split_whitespace();
+    let program = parts.next().unwrap_or("vi").to_string();
+    let args = parts.map(|p| p.to_string()).collect();
+    (program, args)
+}
+
+fn render_tags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|t| format!(" {} ", t).black().on_cyan().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn task_to_editable(task: &Task) -> String {
+    format!(
+        "title: {}\npriority: {:?}\ndue_date: {}\ntags: {}\n\n{}\n",
+        task.title,
+        task.priority,
+        task.due_date,
+        task.tags.join(", "),
+        task.description
+    )
+}
+
+fn apply_edited_content(task: &mut Task, content: &str) {
+    let mut lines = content.lines();
+
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+
+        match key.trim() {
+            "title" => task.title = value.to_string(),
+            "priority" => match value.to_lowercase().as_str() {
+                "low" => task.priority = Priority::Low,
+                "medium" => task.priority = Priority::Medium,
+                "high" => task.priority = Priority::High,
+                _ => {}
+            },
+            "due_date" => {
+                task.due_date = value.to_string();
+                task.parsed_due = parse_due_date(value);
+            }
+            "tags" => task.tags = parse_tags(value),
+            _ => {}
+        }
+    }
+
+    task.description = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+}
+
+fn fuzzy_match_score(query: &str, candidate: &str) -> Option<i32> {
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate = candidate.to_lowercase();
+    let cand_chars: Vec<char> = candidate.chars().collect();
+
+    let mut score = 0i32;
+    let mut cand_idx = 0usize;
+    let mut last_match: Option<usize> = None;
+
+    for qc in query.chars() {
+        let found = (cand_idx..cand_chars.len()).find(|&i| cand_chars[i] == qc)?;
+
+        let bonus = if found == 0 {
+            15
+        } else if matches!(cand_chars[found - 1], ' ' | '/' | '-') {
+            10
+        } else if last_match == Some(found - 1) {
+            8
+        } else {
+            0
+        };
+
+        let skipped = match last_match {
+            Some(last) => found as i32 - last as i32 - 1,
+            None => found as i32,
+        };
+
+        score += 1 + bonus - skipped;
+        last_match = Some(found);
+        cand_idx = found + 1;
+    }
+
+    Some(score)
+}
+
+fn fuzzy_select(prompt: &str, items: &[String]) -> usize {
+    let query: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("{} (type to search, empty for all)", prompt))
+        .allow_empty(true)
+        .interact_text()
+        .unwrap();
+
+    let mut ranked: Vec<(usize, i32)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| fuzzy_match_score(&query, item).map(|score| (i, score)))
+        .collect();
+
+    if ranked.is_empty() {
+        println!("{}", "No matches, showing full list".yellow());
+        ranked = (0..items.len()).map(|i| (i, 0)).collect();
+    } else {
+        ranked.sort_by(|a, b| b.1.cmp(&a.1));
+    }
+
+    let display: Vec<&String> = ranked.iter().map(|(i, _)| &items[*i]).collect();
+    let picked = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(&display)
+        .default(0)
+        .interact()
+        .unwrap();
+
+    ranked[picked].0
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum Priority {
     Low,
@@ -23,10 +175,38 @@ enum Status {
     Done,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DueFilter {
+    Today,
+    ThisWeek,
+    Overdue,
+}
+
+impl DueFilter {
+    fn matches(&self, task: &Task, today: NaiveDate) -> bool {
+        match task.parsed_due {
+            Some(due) => match self {
+                DueFilter::Today => due == today,
+                DueFilter::ThisWeek => due >= today && due <= today + chrono::Duration::days(7),
+                DueFilter::Overdue => due < today,
+            },
+            None => false,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Config {
     github_token: Option<String>,
+    #[serde(default)]
+    gitlab_token: Option<String>,
     repositories: Vec<Repository>,
+    #[serde(default)]
+    last_synced_at: Option<String>,
+    #[serde(default)]
+    sync_remote: Option<String>,
+    #[serde(default)]
+    notifications: NotificationConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -34,6 +214,37 @@ struct Repository {
     owner: String,
     name: String,
     display_name: String,
+    #[serde(default)]
+    kind: ForgeKind,
+    #[serde(default)]
+    base_url: Option<String>,
+}
+
+impl Repository {
+    fn issue_url(&self, number: u64) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("https://github.com/{}/{}/issues/{}", self.owner, self.name, number),
+            ForgeKind::GitLab => format!(
+                "{}/{}/{}/-/issues/{}",
+                self.base_url.as_deref().unwrap_or("https://gitlab.com"),
+                self.owner,
+                self.name,
+                number
+            ),
+        }
+    }
+
+    fn project_url(&self) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("https://github.com/{}/{}/projects", self.owner, self.name),
+            ForgeKind::GitLab => format!(
+                "{}/{}/{}/-/boards",
+                self.base_url.as_deref().unwrap_or("https://gitlab.com"),
+                self.owner,
+                self.name
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -44,14 +255,20 @@ struct Task {
     priority: Priority,
     status: Status,
     due_date: String,
+    #[serde(default)]
+    parsed_due: Option<NaiveDate>,
     github_issue_number: Option<u64>,
     created_at: String,
+    #[serde(default)]
+    updated_at: String,
+    #[serde(default)]
+    tags: Vec<String>,
 }
 
 struct TaskManager {
     tasks: Vec<Task>,
     save_path: std::path::PathBuf,
-    github: Option<Octocrab>,
+    forge: Option<Box<dyn Forge>>,
     config: Config,
     current_repo: Option<Repository>,
 }
@@ -60,22 +277,21 @@ impl TaskManager {
 
     pub async fn new() -> Self {
         println!("{}", "🚀 Welcome to TaskFlow!".bold().magenta());
-        println!("A friendly task manager for any GitHub project");
-        
+        println!("A friendly task manager for any GitHub or GitLab project");
+
         let folders = ProjectDirs::from("com", "taskflow", "tasks")
             .expect("Could not determine config directory");
-        
+
         let save_path = folders.config_dir().to_path_buf();
         fs::create_dir_all(&save_path).expect("Could not create config directory");
-        
+
         let config = Self::load_or_create_config(&save_path);
-        let github = Self::setup_github(&config).await;
         let tasks = Self::load_tasks(&save_path).unwrap_or_else(|_| Vec::new());
-        
+
         let mut manager = TaskManager {
             tasks,
             save_path,
-            github,
+            forge: None,
             config,
             current_repo: None,
         };
@@ -85,27 +301,47 @@ impl TaskManager {
         }
 
         manager.select_repository().await;
+
+        let today = Local::now().date_naive();
+        notify::notify_due_tasks(&manager.tasks, &manager.config.notifications, today);
+
         manager
     }
 
-    async fn setup_github(config: &Config) -> Option<Octocrab> {
-        if let Some(token) = &config.github_token {
-            match Octocrab::builder()
-                .personal_token(token.clone())
-                .build() {
-                    Ok(github) => {
+    async fn connect_forge(&mut self) {
+        let Some(repo) = self.current_repo.clone() else {
+            self.forge = None;
+            return;
+        };
+
+        self.forge = match repo.kind {
+            ForgeKind::GitHub => match &self.config.github_token {
+                Some(token) => match GitHubForge::new(token.clone()) {
+                    Ok(forge) => {
                         println!("{}", "✅ Connected to GitHub!".green());
-                        Some(github)
-                    },
+                        Some(Box::new(forge) as Box<dyn Forge>)
+                    }
                     Err(_) => {
                         println!("{}", "⚠️  GitHub connection failed".yellow());
                         None
                     }
+                },
+                None => {
+                    println!("{}", "No GitHub token configured".yellow());
+                    None
                 }
-        } else {
-            println!("{}", "No GitHub token configured".yellow());
-            None
-        }
+            },
+            ForgeKind::GitLab => match &self.config.gitlab_token {
+                Some(token) => {
+                    println!("{}", "✅ Connected to GitLab!".green());
+                    Some(Box::new(GitLabForge::new(token.clone())) as Box<dyn Forge>)
+                }
+                None => {
+                    println!("{}", "No GitLab token configured".yellow());
+                    None
+                }
+            },
+        };
     }
 
     fn load_or_create_config(path: &std::path::Path) -> Config {
@@ -116,7 +352,11 @@ impl TaskManager {
         } else {
             let config = Config {
                 github_token: None,
+                gitlab_token: None,
                 repositories: Vec::new(),
+                last_synced_at: None,
+                sync_remote: None,
+                notifications: NotificationConfig::default(),
             };
             let data = serde_json::to_string_pretty(&config).expect("Failed to serialize config");
             fs::write(&config_path, data).expect("Failed to write config");
@@ -135,43 +375,125 @@ impl TaskManager {
 
         self.config.github_token = Some(token);
         self.save_config().expect("Failed to save config");
-        self.github = Self::setup_github(&self.config).await;
 
+        self.setup_notifications().await;
         self.add_repository().await;
     }
 
+    async fn setup_notifications(&mut self) {
+        let wants_notifications = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Remind you about due and overdue tasks?")
+            .items(&["Yes", "No"])
+            .default(0)
+            .interact()
+            .unwrap()
+            == 0;
+
+        if !wants_notifications {
+            return;
+        }
+
+        let channels = vec!["Terminal banner", "Desktop notification"];
+        let channel_idx = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How should reminders be delivered?")
+            .items(&channels)
+            .default(0)
+            .interact()
+            .unwrap();
+
+        self.config.notifications = NotificationConfig {
+            enabled: true,
+            channel: if channel_idx == 0 {
+                NotifyChannel::Terminal
+            } else {
+                NotifyChannel::Desktop
+            },
+            window_days: 0,
+        };
+        self.save_config().expect("Failed to save config");
+    }
+
+    fn forge_for(&self, kind: ForgeKind) -> Option<Box<dyn Forge>> {
+        match kind {
+            ForgeKind::GitHub => self
+                .config
+                .github_token
+                .clone()
+                .and_then(|token| GitHubForge::new(token).ok())
+                .map(|f| Box::new(f) as Box<dyn Forge>),
+            ForgeKind::GitLab => self
+                .config
+                .gitlab_token
+                .clone()
+                .map(|token| Box::new(GitLabForge::new(token)) as Box<dyn Forge>),
+        }
+    }
+
     async fn add_repository(&mut self) {
-        println!("\n{}", "Let's add a GitHub repository:".bold());
-        
-    let owner: String = Input::with_theme(&ColorfulTheme::default())
-        .with_prompt("Repository owner (username or organization)")
-        .interact()
-        .unwrap();
-    
-    let name: String = Input::with_theme(&ColorfulTheme::default())
-        .with_prompt("Repository name")
-        .interact()
-        .unwrap();
-    
-    let display_name: String = Input::with_theme(&ColorfulTheme::default())
-        .with_prompt("Display name for this project")
-        .default(name.clone())
-        .interact()
-        .unwrap();
+        println!("\n{}", "Let's add a repository:".bold());
+
+        let forges = vec!["GitHub", "GitLab"];
+        let kind_idx = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Which forge is this repository on?")
+            .items(&forges)
+            .default(0)
+            .interact()
+            .unwrap();
+        let kind = if kind_idx == 0 { ForgeKind::GitHub } else { ForgeKind::GitLab };
+
+        let base_url = if kind == ForgeKind::GitLab {
+            let url: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("GitLab base URL")
+                .default("https://gitlab.com".to_string())
+                .interact_text()
+                .unwrap();
+            Some(url)
+        } else {
+            None
+        };
+
+        let needs_token = match kind {
+            ForgeKind::GitHub => self.config.github_token.is_none(),
+            ForgeKind::GitLab => self.config.gitlab_token.is_none(),
+        };
+        if needs_token {
+            let token: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!("Enter your {:?} token", kind))
+                .interact()
+                .unwrap();
+            match kind {
+                ForgeKind::GitHub => self.config.github_token = Some(token),
+                ForgeKind::GitLab => self.config.gitlab_token = Some(token),
+            }
+            self.save_config().expect("Failed to save config");
+        }
+
+        let owner: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Repository owner (username or organization)")
+            .interact()
+            .unwrap();
+
+        let name: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Repository name")
+            .interact()
+            .unwrap();
 
-        if let Some(github) = &self.github {
+        let display_name: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Display name for this project")
+            .default(name.clone())
+            .interact()
+            .unwrap();
+
+        let repo = Repository { owner, name, display_name, kind, base_url };
+
+        if let Some(forge) = self.forge_for(kind) {
             println!("🔄 Verifying repository access...");
-            match github.repos(owner.clone(), name.clone()).get().await {
+            match forge.verify_repo(&repo).await {
                 Ok(_) => {
                     println!("✅ Repository verified!");
-                    let repo = Repository {
-                        owner,
-                        name,
-                        display_name,
-                    };
                     self.config.repositories.push(repo);
                     self.save_config().expect("Failed to save config");
-                },
+                }
                 Err(_) => {
                     println!("⚠️  Could not access repository. Please check the details and your permissions.");
                 }
@@ -191,16 +513,133 @@ impl TaskManager {
             .map(|r| format!("{} ({}/{})", r.display_name, r.owner, r.name))
             .collect();
 
-        let repo_idx = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Select repository to work with")
-            .items(&repo_choices)
-            .default(0)
-            .interact()
-            .unwrap();
+        let repo_idx = fuzzy_select("Select repository to work with", &repo_choices);
 
         self.current_repo = Some(self.config.repositories[repo_idx].clone());
-        println!("\n{} {}", "🎯 Now working with:".bold(), 
+        println!("\n{} {}", "🎯 Now working with:".bold(),
             self.current_repo.as_ref().unwrap().display_name);
+
+        self.connect_forge().await;
+        self.sync_issues().await;
+    }
+
+    async fn sync_issues(&mut self) {
+        let (Some(repo), Some(forge)) = (self.current_repo.clone(), self.forge.as_ref()) else {
+            return;
+        };
+
+        println!("\n{}", "🔄 Syncing with forge issues...".bold().blue());
+
+        let since = self.config.last_synced_at.as_deref().map(parse_timestamp);
+        let last_sync = since.unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+
+        let issues = match forge.list_issues(&repo, since).await {
+            Ok(issues) => issues,
+            Err(_) => {
+                println!("{}", "⚠️  Couldn't fetch issues from the forge".yellow());
+                return;
+            }
+        };
+
+        let mut imported = 0;
+        let mut reconciled = 0;
+
+        for issue in issues {
+            if let Some(task) = self
+                .tasks
+                .iter_mut()
+                .find(|t| t.github_issue_number == Some(issue.number))
+            {
+                let local_done = task.status == Status::Done;
+                let remote_closed = issue.state == ForgeIssueState::Closed;
+                if local_done == remote_closed {
+                    continue;
+                }
+
+                let local_changed = parse_timestamp(&task.updated_at) > last_sync;
+                let remote_changed = issue.updated_at > last_sync;
+
+                match (local_changed, remote_changed) {
+                    (true, true) => {
+                        let choice = Select::with_theme(&ColorfulTheme::default())
+                            .with_prompt(format!(
+                                "\"{}\" changed both locally and remotely — which wins?",
+                                task.title
+                            ))
+                            .items(&["Keep local", "Keep remote"])
+                            .default(0)
+                            .interact()
+                            .unwrap();
+
+                        if choice == 0 {
+                            Self::push_issue_state(forge, &repo, issue.number, local_done).await;
+                        } else {
+                            task.status = if remote_closed { Status::Done } else { Status::Todo };
+                            task.updated_at = Local::now().to_rfc3339();
+                        }
+                    }
+                    (true, false) => {
+                        Self::push_issue_state(forge, &repo, issue.number, local_done).await;
+                    }
+                    _ => {
+                        task.status = if remote_closed { Status::Done } else { Status::Todo };
+                        task.updated_at = Local::now().to_rfc3339();
+                    }
+                }
+
+                reconciled += 1;
+            } else {
+                let next_id = self.tasks.len();
+                self.tasks.push(Task {
+                    id: next_id,
+                    title: issue.title.clone(),
+                    description: issue.body.clone().unwrap_or_default(),
+                    priority: Priority::Medium,
+                    status: if issue.state == ForgeIssueState::Closed {
+                        Status::Done
+                    } else {
+                        Status::Todo
+                    },
+                    due_date: String::new(),
+                    parsed_due: None,
+                    github_issue_number: Some(issue.number),
+                    created_at: issue.created_at.format("%B %d, %Y").to_string(),
+                    updated_at: issue.updated_at.to_rfc3339(),
+                    tags: issue.labels.clone(),
+                });
+                imported += 1;
+            }
+        }
+
+        self.config.last_synced_at = Some(Local::now().to_rfc3339());
+        self.save_config().expect("Failed to save config");
+        self.save_tasks().expect("Failed to save tasks");
+
+        println!(
+            "✅ Sync complete: {} imported, {} reconciled",
+            imported, reconciled
+        );
+
+        let today = Local::now().date_naive();
+        notify::notify_due_tasks(&self.tasks, &self.config.notifications, today);
+    }
+
+    async fn push_issue_state(forge: &dyn Forge, repo: &Repository, number: u64, done: bool) {
+        let result = if done {
+            forge.close_issue(repo, number).await
+        } else {
+            forge.reopen_issue(repo, number).await
+        };
+
+        if result.is_err() {
+            println!("{}", "⚠️  Couldn't update issue on the forge".yellow());
+        }
+    }
+
+    async fn mirror_tags_to_issue(forge: &dyn Forge, repo: &Repository, issue_number: u64, tags: &[String]) {
+        if forge.add_labels(repo, issue_number, tags).await.is_err() {
+            println!("{}", "⚠️  Couldn't apply labels to issue".yellow());
+        }
     }
 
     async fn add_task(&mut self) {
@@ -231,42 +670,56 @@ impl TaskManager {
             _ => Priority::High,
         };
 
-        let due_date = Input::with_theme(&ColorfulTheme::default())
+        let due_date: String = Input::with_theme(&ColorfulTheme::default())
             .with_prompt("Due date (e.g., 'tomorrow', 'next week')")
             .interact()
             .unwrap();
 
-        let task = Task {
+        let parsed_due = parse_due_date(&due_date);
+        if parsed_due.is_none() {
+            println!("{}", "⚠️  Couldn't understand that date, keeping it as text".yellow());
+        }
+
+        let tags_input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Tags (comma-separated, optional)")
+            .allow_empty(true)
+            .interact()
+            .unwrap();
+        let tags = parse_tags(&tags_input);
+
+        let mut task = Task {
             id: self.tasks.len(),
             title: title.clone(),
             description: description.clone(),
             priority,
             status: Status::Todo,
             due_date,
+            parsed_due,
             github_issue_number: None,
             created_at: Local::now().format("%B %d, %Y").to_string(),
+            updated_at: Local::now().to_rfc3339(),
+            tags,
         };
 
-        if let (Some(github), Some(repo)) = (&self.github, &self.current_repo) {
+        if let (Some(forge), Some(repo)) = (&self.forge, &self.current_repo) {
             if Select::with_theme(&ColorfulTheme::default())
-                .with_prompt("Create GitHub issue?")
+                .with_prompt("Create an issue for this task?")
                 .items(&["Yes", "No"])
                 .default(0)
                 .interact()
-                .unwrap() == 0 
+                .unwrap() == 0
             {
-                match github.issues(&repo.owner, &repo.name)
-                    .create(&title)
-                    .body(&description)
-                    .send()
-                    .await 
-                {
+                match forge.create_issue(repo, &title, &description).await {
                     Ok(issue) => {
-                        println!("✅ GitHub issue created!");
-                        println!("View it at: https://github.com/{}/{}/issues/{}", 
-                            repo.owner, repo.name, issue.number);
+                        println!("✅ Issue created!");
+                        println!("View it at: {}", repo.issue_url(issue.number));
+                        task.github_issue_number = Some(issue.number);
+
+                        if !task.tags.is_empty() {
+                            Self::mirror_tags_to_issue(forge.as_ref(), repo, issue.number, &task.tags).await;
+                        }
                     },
-                    Err(_) => println!("⚠️  Couldn't create GitHub issue"),
+                    Err(_) => println!("⚠️  Couldn't create issue"),
                 }
             }
         }
@@ -290,15 +743,29 @@ impl TaskManager {
     }
 
     fn list_tasks(&self) {
-        if self.tasks.is_empty() {
+        self.list_tasks_filtered(None, None);
+    }
+
+    fn list_tasks_filtered(&self, due_filter: Option<DueFilter>, tag_filter: Option<&str>) {
+        let today = Local::now().date_naive();
+
+        let mut tasks: Vec<&Task> = self.tasks
+            .iter()
+            .filter(|t| due_filter.map_or(true, |f| f.matches(t, today)))
+            .filter(|t| tag_filter.map_or(true, |tag| t.tags.iter().any(|tg| tg == tag)))
+            .collect();
+
+        if tasks.is_empty() {
             println!("\n{}", "No tasks found.".yellow());
             return;
         }
 
+        tasks.sort_by_key(|t| t.parsed_due.unwrap_or(NaiveDate::MAX));
+
         println!("\n{}", "📋 Your Tasks".bold().blue());
         println!("{}", "=".repeat(50));
 
-        for task in &self.tasks {
+        for task in tasks {
             let status_icon = match task.status {
                 Status::Todo => "🆕",
                 Status::InProgress => "🔄",
@@ -318,19 +785,63 @@ impl TaskManager {
                 "".into()
             };
 
+            let due_text = match task.parsed_due {
+                Some(due) if due < today => task.due_date.red(),
+                Some(due) if due <= today + chrono::Duration::hours(48) => task.due_date.yellow(),
+                _ => task.due_date.cyan(),
+            };
+
+            let tags_text = if !task.tags.is_empty() {
+                format!("\n{}", render_tags(&task.tags))
+            } else {
+                String::new()
+            };
+
             println!(
-                "\n{} {} {}\n{}\nDue: {}\nCreated: {}\n",
+                "\n{} {} {}\n{}\nDue: {}\nCreated: {}{}\n",
                 status_icon,
                 task.title.bold(),
                 priority_icon,
                 description_text,
-                task.due_date.cyan(),
-                task.created_at.dimmed()
+                due_text,
+                task.created_at.dimmed(),
+                tags_text
             );
         }
     }
 
-    fn update_task(&mut self) {
+    fn all_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.tasks.iter().flat_map(|t| t.tags.clone()).collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    fn filter_by_tag(&self) {
+        let tags = self.all_tags();
+        if tags.is_empty() {
+            println!("\n{}", "No tags to filter by yet.".yellow());
+            return;
+        }
+
+        let tag_idx = fuzzy_select("Select a tag", &tags);
+        let tag = &tags[tag_idx];
+
+        let views = vec!["List view", "Kanban view"];
+        let view_idx = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How would you like to view it?")
+            .items(&views)
+            .default(0)
+            .interact()
+            .unwrap();
+
+        match view_idx {
+            0 => self.list_tasks_filtered(None, Some(tag)),
+            _ => self.show_kanban_board_filtered(Some(tag)),
+        }
+    }
+
+    async fn update_task(&mut self) {
         if self.tasks.is_empty() {
             println!("\n{}", "No tasks to update.".yellow());
             return;
@@ -341,37 +852,346 @@ impl TaskManager {
             .map(|t| format!("{}: {}", t.id, t.title))
             .collect();
 
-        let task_idx = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Select task to update")
-            .items(&task_list)
+        let task_idx = fuzzy_select("Select task to update", &task_list);
+
+        let fields = vec!["Status", "Tags"];
+        let field_idx = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What would you like to update?")
+            .items(&fields)
+            .default(0)
             .interact()
             .unwrap();
 
-        let statuses = vec!["Todo", "In Progress", "Needs Help", "Done"];
-        let status_idx = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Update status")
-            .items(&statuses)
+        match field_idx {
+            0 => {
+                let statuses = vec!["Todo", "In Progress", "Needs Help", "Done"];
+                let status_idx = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Update status")
+                    .items(&statuses)
+                    .interact()
+                    .unwrap();
+
+                self.tasks[task_idx].status = match status_idx {
+                    0 => Status::Todo,
+                    1 => Status::InProgress,
+                    2 => Status::NeedsHelp,
+                    _ => Status::Done,
+                };
+            }
+            _ => {
+                let current = self.tasks[task_idx].tags.join(", ");
+                let tags_input: String = Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Tags (comma-separated)")
+                    .with_initial_text(current)
+                    .allow_empty(true)
+                    .interact()
+                    .unwrap();
+                self.tasks[task_idx].tags = parse_tags(&tags_input);
+
+                let issue_number = self.tasks[task_idx].github_issue_number;
+                if let (Some(issue_number), Some(forge), Some(repo)) =
+                    (issue_number, &self.forge, &self.current_repo)
+                {
+                    Self::mirror_tags_to_issue(forge.as_ref(), repo, issue_number, &self.tasks[task_idx].tags).await;
+                }
+            }
+        }
+
+        self.tasks[task_idx].updated_at = Local::now().to_rfc3339();
+        self.save_tasks().expect("Failed to save tasks");
+        println!("\n{}", "✅ Task updated!".green());
+    }
+
+    async fn edit_task(&mut self) {
+        if self.tasks.is_empty() {
+            println!("\n{}", "No tasks to edit.".yellow());
+            return;
+        }
+
+        let task_list: Vec<String> = self.tasks
+            .iter()
+            .map(|t| format!("{}: {}", t.id, t.title))
+            .collect();
+
+        let task_idx = fuzzy_select("Select task to edit", &task_list);
+
+        let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        let edit_path = env::temp_dir().join(format!("taskflow-task-{}.md", self.tasks[task_idx].id));
+        fs::write(&edit_path, task_to_editable(&self.tasks[task_idx])).expect("Failed to write temp file");
+
+        let (editor_program, editor_args) = split_editor_command(&editor);
+        let status = Command::new(editor_program)
+            .args(editor_args)
+            .arg(&edit_path)
+            .status();
+        if status.map(|s| !s.success()).unwrap_or(true) {
+            println!("{}", "⚠️  Editor exited without saving".yellow());
+            let _ = fs::remove_file(&edit_path);
+            return;
+        }
+
+        let content = fs::read_to_string(&edit_path).expect("Failed to read edited task");
+        let _ = fs::remove_file(&edit_path);
+
+        apply_edited_content(&mut self.tasks[task_idx], &content);
+        self.tasks[task_idx].updated_at = Local::now().to_rfc3339();
+        self.save_tasks().expect("Failed to save tasks");
+        println!("\n{}", "✅ Task updated!".green());
+
+        let issue_number = self.tasks[task_idx].github_issue_number;
+        if let (Some(issue_number), Some(forge), Some(repo)) =
+            (issue_number, &self.forge, &self.current_repo)
+        {
+            if Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Push these changes to the linked issue?")
+                .items(&["Yes", "No"])
+                .default(0)
+                .interact()
+                .unwrap() == 0
+            {
+                let result = forge
+                    .update_issue(
+                        repo,
+                        issue_number,
+                        &self.tasks[task_idx].title,
+                        &self.tasks[task_idx].description,
+                    )
+                    .await;
+
+                match result {
+                    Ok(_) => println!("✅ Issue updated!"),
+                    Err(_) => println!("{}", "⚠️  Couldn't update issue".yellow()),
+                }
+            }
+        }
+    }
+
+    fn filter_by_due_date(&self) {
+        let options = vec!["Due today", "This week", "Overdue"];
+        let idx = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Filter which tasks?")
+            .items(&options)
+            .default(0)
             .interact()
             .unwrap();
 
-        self.tasks[task_idx].status = match status_idx {
-            0 => Status::Todo,
-            1 => Status::InProgress,
-            2 => Status::NeedsHelp,
-            _ => Status::Done,
+        let filter = match idx {
+            0 => DueFilter::Today,
+            1 => DueFilter::ThisWeek,
+            _ => DueFilter::Overdue,
         };
 
-        self.save_tasks().expect("Failed to save tasks");
-        println!("\n{}", "✅ Task updated!".green());
+        self.list_tasks_filtered(Some(filter), None);
+    }
+
+    async fn remind_me(&mut self) {
+        let window: i64 = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Show tasks due within how many days? (0 = today and overdue)")
+            .default(self.config.notifications.window_days)
+            .interact_text()
+            .unwrap();
+
+        let today = Local::now().date_naive();
+        let due = notify::due_tasks(&self.tasks, window, today);
+
+        if due.is_empty() {
+            println!("\n{}", "✅ Nothing due — you're all caught up!".green());
+            return;
+        }
+
+        notify::terminal_banner(&due, today);
+
+        if self.config.notifications.enabled
+            && Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Send a reminder notification for these too?")
+                .items(&["Yes", "No"])
+                .default(1)
+                .interact()
+                .unwrap()
+                == 0
+        {
+            let reminder_config = NotificationConfig {
+                window_days: window,
+                ..self.config.notifications.clone()
+            };
+            notify::notify_due_tasks(&self.tasks, &reminder_config, today);
+        }
     }
 
     fn save_config(&self) -> Result<(), Box<dyn std::error::Error>> {
         let config_path = self.save_path.join("config.json");
         let data = serde_json::to_string_pretty(&self.config)?;
         fs::write(config_path, data)?;
+        self.git_commit_all("taskflow: config updated");
         Ok(())
     }
 
+    fn ensure_git_repo(&self) {
+        let Some(remote) = &self.config.sync_remote else {
+            return;
+        };
+
+        let is_new_repo = !self.save_path.join(".git").exists();
+
+        if is_new_repo {
+            let _ = Command::new("git")
+                .args(["init", "-q"])
+                .current_dir(&self.save_path)
+                .status();
+        }
+
+        let has_origin = Command::new("git")
+            .args(["remote", "get-url", "origin"])
+            .current_dir(&self.save_path)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+
+        if !has_origin {
+            let _ = Command::new("git")
+                .args(["remote", "add", "origin", remote])
+                .current_dir(&self.save_path)
+                .status();
+        }
+
+        if is_new_repo {
+            self.adopt_remote_history();
+        }
+    }
+
+    /// Fetches the remote's existing branch into a fresh local repo, so the
+    /// first commit made here shares history with whatever other machines
+    /// already pushed instead of starting an unrelated history that
+    /// `git pull` can never reconcile.
+    fn adopt_remote_history(&self) {
+        let fetched = Command::new("git")
+            .args(["fetch", "-q", "origin"])
+            .current_dir(&self.save_path)
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+
+        if !fetched {
+            return;
+        }
+
+        let _ = Command::new("git")
+            .args(["remote", "set-head", "origin", "-a"])
+            .current_dir(&self.save_path)
+            .status();
+
+        let has_remote_head = Command::new("git")
+            .args(["rev-parse", "--verify", "-q", "origin/HEAD"])
+            .current_dir(&self.save_path)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+
+        if has_remote_head {
+            let _ = Command::new("git")
+                .args(["checkout", "-q", "-B", "main", "origin/HEAD"])
+                .current_dir(&self.save_path)
+                .status();
+        }
+    }
+
+    fn git_commit_all(&self, message: &str) {
+        if self.config.sync_remote.is_none() {
+            return;
+        }
+
+        self.ensure_git_repo();
+
+        // `git add` fails (and stages nothing) if any path given to it doesn't
+        // exist, e.g. tasks.json before the first task is ever created.
+        let tasks_path = self.save_path.join("tasks.json");
+        if !tasks_path.exists() {
+            let _ = fs::write(&tasks_path, "[]");
+        }
+
+        let _ = Command::new("git")
+            .args(["add", "tasks.json", "config.json"])
+            .current_dir(&self.save_path)
+            .status();
+        let _ = Command::new("git")
+            .args(["commit", "-q", "-m", message])
+            .current_dir(&self.save_path)
+            .status();
+    }
+
+    async fn sync_tasks_git(&mut self) {
+        if self.config.sync_remote.is_none() {
+            let remote: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Git remote URL to sync tasks with (blank to skip)")
+                .allow_empty(true)
+                .interact()
+                .unwrap();
+
+            if remote.trim().is_empty() {
+                println!("{}", "No remote configured, skipping sync.".yellow());
+                return;
+            }
+
+            self.config.sync_remote = Some(remote);
+            self.save_config().expect("Failed to save config");
+        }
+
+        self.ensure_git_repo();
+        self.git_commit_all(&format!("taskflow: {} tasks updated", self.tasks.len()));
+
+        println!("\n{}", "🔃 Pulling remote changes...".bold().blue());
+        let pull = Command::new("git")
+            .args(["pull", "--no-edit", "--no-rebase", "origin", "HEAD"])
+            .current_dir(&self.save_path)
+            .output();
+
+        let tasks_path = self.save_path.join("tasks.json");
+        let has_conflict = fs::read_to_string(&tasks_path)
+            .map(|data| data.contains("<<<<<<<"))
+            .unwrap_or(false);
+
+        if has_conflict {
+            println!(
+                "{}",
+                format!(
+                    "⚠️  Merge conflict in tasks.json — resolve it by hand at {}",
+                    tasks_path.display()
+                )
+                .red()
+            );
+            return;
+        }
+
+        match pull {
+            Ok(output) if !output.status.success() => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                println!(
+                    "{}",
+                    format!("⚠️  Pull failed: {}", stderr.lines().next().unwrap_or("unknown error")).yellow()
+                );
+            }
+            Err(_) => println!("{}", "⚠️  Couldn't run git pull".yellow()),
+            _ => {}
+        }
+
+        // The pull may have brought in a newer tasks.json — reload the tasks before pushing.
+        self.tasks = Self::load_tasks(&self.save_path).unwrap_or_else(|_| Vec::new());
+
+        println!("{}", "⬆️  Pushing local changes...".bold().blue());
+        let push = Command::new("git")
+            .args(["push", "-u", "origin", "HEAD"])
+            .current_dir(&self.save_path)
+            .status();
+
+        match push {
+            Ok(status) if status.success() => println!("{}", "✅ Tasks synced!".green()),
+            _ => println!("{}", "⚠️  Couldn't push to remote".yellow()),
+        }
+
+        let today = Local::now().date_naive();
+        notify::notify_due_tasks(&self.tasks, &self.config.notifications, today);
+    }
+
     fn load_tasks(path: &std::path::Path) -> Result<Vec<Task>, Box<dyn std::error::Error>> {
         let file_path = path.join("tasks.json");
         if !file_path.exists() {
@@ -379,7 +1199,16 @@ impl TaskManager {
         }
         
         let data = fs::read_to_string(file_path)?;
-        let tasks = serde_json::from_str(&data)?;
+        let mut tasks: Vec<Task> = serde_json::from_str(&data)?;
+
+        // Tasks saved before `parsed_due` existed come back with it unset;
+        // re-run the parser over their raw string so old data still sorts.
+        for task in &mut tasks {
+            if task.parsed_due.is_none() {
+                task.parsed_due = parse_due_date(&task.due_date);
+            }
+        }
+
         Ok(tasks)
     }
 
@@ -387,6 +1216,7 @@ impl TaskManager {
         let file_path = self.save_path.join("tasks.json");
         let data = serde_json::to_string_pretty(&self.tasks)?;
         fs::write(file_path, data)?;
+        self.git_commit_all("taskflow: tasks updated");
         Ok(())
     }
 
@@ -395,27 +1225,39 @@ impl TaskManager {
             let choices = vec![
                 "✨ Add new task",
                 "📋 List tasks",
+                "📅 Filter by due date",
+                "🏷️  Filter by tag",
                 "🔄 Update task",
+                "📝 Edit task in $EDITOR",
                 "🎨 Visualize Project",
+                "🔃 Sync issues",
+                "🔁 Sync tasks (git)",
                 "📂 Switch repository",
                 "⚙️  Add new repository",
+                "🔔 Remind me",
                 "👋 Exit",
             ];
-    
+
             let choice = Select::with_theme(&ColorfulTheme::default())
                 .with_prompt("What would you like to do?")
                 .items(&choices)
                 .default(0)
                 .interact()
                 .unwrap();
-    
+
             match choice {
                 0 => self.add_task().await,
                 1 => self.list_tasks(),
-                2 => self.update_task(),
-                3 => self.visualize_project().await,
-                4 => self.select_repository().await,
-                5 => self.add_repository().await,
+                2 => self.filter_by_due_date(),
+                3 => self.filter_by_tag(),
+                4 => self.update_task().await,
+                5 => self.edit_task().await,
+                6 => self.visualize_project().await,
+                7 => self.sync_issues().await,
+                8 => self.sync_tasks_git().await,
+                9 => self.select_repository().await,
+                10 => self.add_repository().await,
+                11 => self.remind_me().await,
                 _ => break,
             }
         }
@@ -424,7 +1266,7 @@ impl TaskManager {
     async fn visualize_project(&self) {
         let choices = vec![
             "🎨 View Kanban Board",
-            "🌐 Open in GitHub",
+            "🌐 Open in browser",
             "🔄 Create/Update Project Board",
             "🔙 Back"
         ];
@@ -439,12 +1281,16 @@ impl TaskManager {
         match choice {
             0 => self.show_kanban_board(),
             1 => self.open_project_in_browser().await,
-            2 => self.create_github_project().await,
+            2 => self.create_project_board().await,
             _ => return,
         }
     }
 
     fn show_kanban_board(&self) {
+        self.show_kanban_board_filtered(None);
+    }
+
+    fn show_kanban_board_filtered(&self, tag_filter: Option<&str>) {
         println!("\n{}", "🎨 Project Kanban Board".bold().magenta());
         println!("{}", "=".repeat(80));
 
@@ -458,6 +1304,14 @@ impl TaskManager {
         let width = 20;
         let separator = "│";
 
+        let in_column = |status: &Status| -> Vec<&Task> {
+            self.tasks
+                .iter()
+                .filter(|t| t.status == *status)
+                .filter(|t| tag_filter.map_or(true, |tag| t.tags.iter().any(|tg| tg == tag)))
+                .collect()
+        };
+
         // Print header
         for (title, _) in &columns {
             print!("{:^width$}{}", title.bold(), separator, width = width);
@@ -467,22 +1321,14 @@ impl TaskManager {
         // Get max tasks in any column
         let max_tasks = columns
             .iter()
-            .map(|(_, status)| {
-                self.tasks
-                    .iter()
-                    .filter(|t| t.status == *status)
-                    .count()
-            })
+            .map(|(_, status)| in_column(status).len())
             .max()
             .unwrap_or(0);
 
         // Print tasks in columns
         for i in 0..max_tasks {
             for (_, status) in &columns {
-                let task = self.tasks
-                    .iter()
-                    .filter(|t| t.status == *status)
-                    .nth(i);
+                let task = in_column(status).into_iter().nth(i);
 
                 if let Some(task) = task {
                     let priority_color = match task.priority {
@@ -496,17 +1342,20 @@ impl TaskManager {
                 }
             }
             println!();
+
+            for (_, status) in &columns {
+                let task = in_column(status).into_iter().nth(i);
+                let tags_text = task.map(|t| render_tags(&t.tags)).unwrap_or_default();
+                print!("{:width$}{}", tags_text, separator, width = width);
+            }
+            println!();
         }
     }
 
     async fn open_project_in_browser(&self) {
         if let Some(repo) = &self.current_repo {
-            let project_url = format!(
-                "https://github.com/{}/{}/projects",
-                repo.owner,
-                repo.name
-            );
-            
+            let project_url = repo.project_url();
+
             println!("🌐 Opening project in browser...");
             if webbrowser::open(&project_url).is_ok() {
                 println!("✅ Browser opened successfully!");
@@ -516,29 +1365,27 @@ impl TaskManager {
         }
     }
 
-    async fn create_github_project(&self) {
-        if let (Some(github), Some(repo)) = (&self.github, &self.current_repo) {
-            println!("\n{}", "✨ Creating new GitHub Project".bold().green());
-            
+    async fn create_project_board(&self) {
+        if let (Some(forge), Some(repo)) = (&self.forge, &self.current_repo) {
+            println!("\n{}", "✨ Creating new Project Board".bold().green());
+
             let name = Input::<String>::with_theme(&ColorfulTheme::default())
                 .with_prompt("Project name")
                 .default("TaskFlow Board".to_string())
                 .interact_text()
                 .unwrap();
-    
+
             let description = Input::<String>::with_theme(&ColorfulTheme::default())
                 .with_prompt("Project description")
                 .default("Task management board".to_string())
                 .interact_text()
                 .unwrap();
-    
+
             // Create an issue to track project setup
-            let setup_issue = github.issues(&repo.owner, &repo.name)
-                .create(&format!("Setup: {}", name))
-                .body(&format!("Project Board Setup\n\n{}", description))
-                .send()
+            let setup_issue = forge
+                .create_issue(repo, &format!("Setup: {}", name), &format!("Project Board Setup\n\n{}", description))
                 .await;
-    
+
             match setup_issue {
                 Ok(_) => {
                     println!("✅ Project tracking issue created!");
@@ -547,9 +1394,9 @@ impl TaskManager {
                     println!("├── 🔄 In Progress");
                     println!("├── 🆘 Needs Help");
                     println!("└── ✅ Done");
-                    
+
                     println!("\n💡 Tip: View and manage your project at:");
-                    println!("https://github.com/{}/{}/projects", repo.owner, repo.name);
+                    println!("{}", repo.project_url());
                 },
                 Err(_) => println!("⚠️  Couldn't create project setup"),
             }
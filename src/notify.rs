@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{Status, Task};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyChannel {
+    Terminal,
+    Desktop,
+}
+
+impl Default for NotifyChannel {
+    fn default() -> Self {
+        NotifyChannel::Terminal
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationConfig {
+    pub enabled: bool,
+    pub channel: NotifyChannel,
+    pub window_days: i64,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        NotificationConfig {
+            enabled: false,
+            channel: NotifyChannel::Terminal,
+            window_days: 0,
+        }
+    }
+}
+
+pub fn notify_due_tasks(tasks: &[Task], config: &NotificationConfig, today: NaiveDate) {
+    if !config.enabled {
+        return;
+    }
+
+    let due = due_tasks(tasks, config.window_days, today);
+    if due.is_empty() {
+        return;
+    }
+
+    match config.channel {
+        NotifyChannel::Terminal => terminal_banner(&due, today),
+        NotifyChannel::Desktop => {
+            if desktop_notification(&due).is_err() {
+                println!("{}", "⚠️  Couldn't show desktop notification, falling back to terminal".yellow());
+                terminal_banner(&due, today);
+            }
+        }
+    }
+}
+
+pub fn due_tasks(tasks: &[Task], window_days: i64, today: NaiveDate) -> Vec<&Task> {
+    tasks
+        .iter()
+        .filter(|t| t.status != Status::Done)
+        .filter(|t| match t.parsed_due {
+            Some(due) => due <= today + chrono::Duration::days(window_days),
+            None => false,
+        })
+        .collect()
+}
+
+pub fn terminal_banner(due: &[&Task], today: NaiveDate) {
+    println!("\n{}", "🔔 Tasks needing attention".bold().yellow());
+    println!("{}", "=".repeat(50));
+    for task in due {
+        let label = match task.parsed_due {
+            Some(d) if d < today => "OVERDUE".red().bold(),
+            _ => "DUE".yellow().bold(),
+        };
+        println!("{} {} ({})", label, task.title.bold(), task.due_date);
+    }
+}
+
+fn desktop_notification(due: &[&Task]) -> Result<(), notify_rust::error::Error> {
+    let body = due
+        .iter()
+        .map(|t| format!("• {} ({})", t.title, t.due_date))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    notify_rust::Notification::new()
+        .summary(&format!("TaskFlow: {} task(s) due", due.len()))
+        .body(&body)
+        .show()
+        .map(|_| ())
+}